@@ -0,0 +1,314 @@
+//! Async counterpart to [`super::StreamReaders`], gated behind the `futures`
+//! feature so synchronous users don't pay for the `futures` dependency.
+
+use std::{
+    io::{self, SeekFrom},
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::io::{AsyncRead, AsyncSeek, AsyncSeekExt};
+
+struct AsyncBoxReader<R> {
+    reader: R,
+    len: u64,
+}
+
+/// Async version of [`super::StreamReaders`]: presents several
+/// `AsyncRead + AsyncSeek` streams, pushed in order, as one concatenated
+/// stream.
+#[derive(Default)]
+pub struct AsyncStreamReaders<R> {
+    buf: Vec<AsyncBoxReader<R>>,
+    /// Index of the segment the cursor currently sits in, or `buf.len()` at EOF.
+    index: usize,
+    /// Offset within the current segment (`buf[index]`).
+    seek: u64,
+    /// `starts[i]` is the cumulative absolute offset at which `buf[i]` begins.
+    starts: Vec<u64>,
+    len: u64,
+    /// Set when `poll_read` advances into `buf[index]` without having
+    /// repositioned it: that segment may have been left mid-stream by a
+    /// previous seek, so it must be seeked back to `0` before it's read.
+    needs_reseek: bool,
+}
+
+impl<R: AsyncRead + AsyncSeek + Unpin> AsyncStreamReaders<R> {
+    /// Create an empty `AsyncStreamReaders`.
+    pub fn new() -> AsyncStreamReaders<R> {
+        Self {
+            buf: Vec::new(),
+            index: 0,
+            seek: 0,
+            starts: Vec::new(),
+            len: 0,
+            needs_reseek: false,
+        }
+    }
+    /// Appends an element, seeking it to measure its length.
+    pub async fn push(&mut self, mut value: R) -> io::Result<()> {
+        let len = value.seek(SeekFrom::End(0)).await?;
+        value.seek(SeekFrom::Start(0)).await?;
+        if len > 0 {
+            self.starts.push(self.len);
+            self.len += len;
+            self.buf.push(AsyncBoxReader { reader: value, len });
+        }
+        Ok(())
+    }
+    /// Return `true` if no element
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+    /// Return the length of the stream
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+    /// Return the position in O(1), mirroring the sync `StreamReaders::pos`.
+    pub fn pos(&self) -> u64 {
+        match self.starts.get(self.index) {
+            Some(start) => start + self.seek,
+            None => self.len,
+        }
+    }
+}
+
+impl<R: AsyncRead + AsyncSeek + Unpin> AsyncRead for AsyncStreamReaders<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        let mut filled = 0usize;
+        while filled < buf.len() {
+            if this.index >= this.buf.len() {
+                break;
+            }
+            if this.needs_reseek {
+                // `seek_to_abs` (in `poll_seek`) only repositions the segment
+                // it lands in and leaves every other segment wherever it was
+                // last touched, so a segment entered by advancing past the
+                // previous one may still be mid-stream from an earlier seek.
+                match Pin::new(&mut this.buf[this.index].reader)
+                    .poll_seek(cx, SeekFrom::Start(0))
+                {
+                    Poll::Pending => {
+                        return if filled == 0 {
+                            Poll::Pending
+                        } else {
+                            Poll::Ready(Ok(filled))
+                        };
+                    }
+                    Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                    Poll::Ready(Ok(_)) => this.needs_reseek = false,
+                }
+            }
+            let segment = &mut this.buf[this.index];
+            match Pin::new(&mut segment.reader).poll_read(cx, &mut buf[filled..]) {
+                Poll::Pending => {
+                    // Only yield if we haven't made any progress yet; a
+                    // partial fill is a valid (short) read.
+                    return if filled == 0 {
+                        Poll::Pending
+                    } else {
+                        Poll::Ready(Ok(filled))
+                    };
+                }
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Ready(Ok(0)) => {
+                    // Segment exhausted: move on and keep filling `buf` from
+                    // the next one.
+                    this.index += 1;
+                    this.seek = 0;
+                    this.needs_reseek = true;
+                }
+                Poll::Ready(Ok(n)) => {
+                    this.seek += n as u64;
+                    filled += n;
+                    if this.seek >= segment.len {
+                        this.index += 1;
+                        this.seek = 0;
+                        this.needs_reseek = true;
+                    }
+                }
+            }
+        }
+        Poll::Ready(Ok(filled))
+    }
+}
+
+impl<R: AsyncRead + AsyncSeek + Unpin> AsyncSeek for AsyncStreamReaders<R> {
+    fn poll_seek(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        pos: SeekFrom,
+    ) -> Poll<io::Result<u64>> {
+        let this = self.get_mut();
+        let target = match pos {
+            SeekFrom::Start(p) => p,
+            SeekFrom::Current(p) => {
+                let cur = this.pos();
+                if p >= 0 {
+                    cur.saturating_add(p as u64)
+                } else {
+                    cur.saturating_sub(p.unsigned_abs())
+                }
+            }
+            SeekFrom::End(p) => {
+                if p >= 0 {
+                    this.len.saturating_add(p as u64)
+                } else {
+                    this.len.saturating_sub(p.unsigned_abs())
+                }
+            }
+        }
+        .min(this.len);
+
+        if this.buf.is_empty() || target == this.len {
+            this.index = this.buf.len();
+            this.seek = 0;
+            return Poll::Ready(Ok(target));
+        }
+
+        let index = match this.starts.binary_search(&target) {
+            Ok(i) => i,
+            Err(i) => i - 1,
+        };
+        let local = target - this.starts[index];
+        match Pin::new(&mut this.buf[index].reader).poll_seek(cx, SeekFrom::Start(local)) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(Err(e)) => Poll::Ready(Err(e)),
+            Poll::Ready(Ok(_)) => {
+                this.index = index;
+                this.seek = local;
+                this.needs_reseek = false;
+                Poll::Ready(Ok(target))
+            }
+        }
+    }
+}
+
+#[allow(unused)]
+mod test {
+    use super::*;
+    use futures::{
+        executor::block_on,
+        io::{AsyncReadExt, Cursor},
+    };
+
+    async fn three_segments() -> io::Result<AsyncStreamReaders<Cursor<Vec<u8>>>> {
+        let mut readers = AsyncStreamReaders::new();
+        readers.push(Cursor::new(b"AAAA".to_vec())).await?;
+        readers.push(Cursor::new(b"BBBB".to_vec())).await?;
+        readers.push(Cursor::new(b"WXYZ".to_vec())).await?;
+        Ok(readers)
+    }
+
+    #[test]
+    fn test_read_fills_across_segment_boundary() -> io::Result<()> {
+        block_on(async {
+            let mut readers = three_segments().await?;
+            let mut buf = [0u8; 6];
+            readers.read_exact(&mut buf).await?;
+            assert_eq!(&buf, b"AAAABB");
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_seek_then_read_reseeks_touched_segment() -> io::Result<()> {
+        block_on(async {
+            let mut readers = three_segments().await?;
+            // Touch the 3rd segment and leave its reader mid-segment.
+            readers.seek(SeekFrom::Start(9)).await?;
+            let mut one = [0u8; 1];
+            readers.read_exact(&mut one).await?;
+
+            readers.seek(SeekFrom::Start(0)).await?;
+            let mut buf = Vec::new();
+            readers.read_to_end(&mut buf).await?;
+            assert_eq!(buf, b"AAAABBBBWXYZ");
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_seek_past_end_clamps_to_len() -> io::Result<()> {
+        block_on(async {
+            let mut readers = three_segments().await?;
+            let pos = readers.seek(SeekFrom::Start(100)).await?;
+            assert_eq!(pos, readers.len());
+            let mut buf = [0u8; 1];
+            assert_eq!(readers.read(&mut buf).await?, 0);
+            Ok(())
+        })
+    }
+
+    /// An `AsyncRead`/`AsyncSeek` wrapper that reports `Poll::Pending` on its
+    /// very first `poll_read`, waking the task immediately so the executor
+    /// retries. Models an inner reader whose data isn't ready yet.
+    struct PendingOnceReader<R> {
+        inner: R,
+        pending_done: bool,
+    }
+
+    impl<R: AsyncRead + Unpin> AsyncRead for PendingOnceReader<R> {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut [u8],
+        ) -> Poll<io::Result<usize>> {
+            if !self.pending_done {
+                self.pending_done = true;
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+            Pin::new(&mut self.inner).poll_read(cx, buf)
+        }
+    }
+
+    impl<R: AsyncSeek + Unpin> AsyncSeek for PendingOnceReader<R> {
+        fn poll_seek(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            pos: SeekFrom,
+        ) -> Poll<io::Result<u64>> {
+            Pin::new(&mut self.get_mut().inner).poll_seek(cx, pos)
+        }
+    }
+
+    #[test]
+    fn test_pending_mid_segment_is_propagated_not_looped() -> io::Result<()> {
+        block_on(async {
+            let mut readers = AsyncStreamReaders::new();
+            readers
+                .push(PendingOnceReader {
+                    inner: Cursor::new(b"AAAA".to_vec()),
+                    pending_done: false,
+                })
+                .await?;
+            readers
+                .push(PendingOnceReader {
+                    inner: Cursor::new(b"BBBB".to_vec()),
+                    pending_done: true,
+                })
+                .await?;
+
+            // Start mid-segment so the first segment's Pending arrives
+            // partway through it.
+            readers.seek(SeekFrom::Start(2)).await?;
+            let waker = futures::task::noop_waker();
+            let mut cx = Context::from_waker(&waker);
+            let mut buf = [0u8; 6];
+            // Nothing is filled yet, so the Pending is handed back to the
+            // caller instead of being retried inside `poll_read`.
+            assert!(Pin::new(&mut readers).poll_read(&mut cx, &mut buf).is_pending());
+
+            // The retried read then completes across both segments.
+            readers.read_exact(&mut buf).await?;
+            assert_eq!(&buf, b"AABBBB");
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,133 @@
+use std::io::{Error, ErrorKind, Read, Result, Seek, SeekFrom};
+
+/// A view over `[start, end)` of an underlying `Read + Seek` stream, hiding
+/// everything outside that window as if the stream only ever contained it.
+pub struct BoundedReader<R> {
+    reader: R,
+    start: u64,
+    end: u64,
+    /// Absolute position within the underlying stream.
+    pos: u64,
+}
+
+impl<R: Read + Seek> BoundedReader<R> {
+    /// Wraps `reader`, exposing only the window `[start, end)`.
+    ///
+    /// The underlying reader is seeked to `start` immediately so the bounded
+    /// view starts at its own position `0`. Returns an error if `start >
+    /// end`.
+    pub fn new(mut reader: R, start: u64, end: u64) -> Result<BoundedReader<R>> {
+        if start > end {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("BoundedReader: start ({start}) is past end ({end})"),
+            ));
+        }
+        reader.seek(SeekFrom::Start(start))?;
+        Ok(BoundedReader {
+            reader,
+            start,
+            end,
+            pos: start,
+        })
+    }
+    /// Return the length of the window, `end - start`.
+    pub fn len(&self) -> u64 {
+        self.end - self.start
+    }
+    /// Return `true` if the window is empty.
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+}
+
+impl<R: Read + Seek> Read for BoundedReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let remaining = self.end.saturating_sub(self.pos);
+        if remaining == 0 {
+            return Ok(0);
+        }
+        let cap = remaining.min(buf.len() as u64) as usize;
+        let len = self.reader.read(&mut buf[..cap])?;
+        self.pos += len as u64;
+        Ok(len)
+    }
+}
+
+impl<R: Read + Seek> Seek for BoundedReader<R> {
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        let target = match pos {
+            SeekFrom::Start(p) => self.start.saturating_add(p),
+            SeekFrom::End(p) => {
+                if p >= 0 {
+                    self.end.saturating_add(p as u64)
+                } else {
+                    self.end.saturating_sub(p.unsigned_abs())
+                }
+            }
+            SeekFrom::Current(p) => {
+                if p >= 0 {
+                    self.pos.saturating_add(p as u64)
+                } else {
+                    self.pos.saturating_sub(p.unsigned_abs())
+                }
+            }
+        }
+        .clamp(self.start, self.end);
+        self.pos = self.reader.seek(SeekFrom::Start(target))?;
+        Ok(self.pos - self.start)
+    }
+}
+
+#[allow(unused)]
+mod test {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_read_window_only() -> Result<()> {
+        let inner = Cursor::new(b"0123456789".to_vec());
+        let mut bounded = BoundedReader::new(inner, 2, 6)?;
+        assert_eq!(bounded.len(), 4);
+        let mut buf = Vec::new();
+        bounded.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"2345");
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek_rebased_on_window() -> Result<()> {
+        let inner = Cursor::new(b"0123456789".to_vec());
+        let mut bounded = BoundedReader::new(inner, 2, 6)?;
+
+        assert_eq!(bounded.seek(SeekFrom::Start(0))?, 0);
+        let mut one = [0u8; 1];
+        bounded.read_exact(&mut one)?;
+        assert_eq!(&one, b"2");
+
+        assert_eq!(bounded.seek(SeekFrom::End(0))?, bounded.len());
+        assert_eq!(bounded.read(&mut one)?, 0);
+
+        assert_eq!(bounded.seek(SeekFrom::Current(-2))?, 2);
+        bounded.read_exact(&mut one)?;
+        assert_eq!(&one, b"4");
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek_clamped_to_window() -> Result<()> {
+        let inner = Cursor::new(b"0123456789".to_vec());
+        let mut bounded = BoundedReader::new(inner, 2, 6)?;
+
+        assert_eq!(bounded.seek(SeekFrom::Start(1000))?, bounded.len());
+        assert_eq!(bounded.seek(SeekFrom::Current(-1000))?, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_rejects_start_past_end() {
+        let inner = Cursor::new(b"0123456789".to_vec());
+        let err = BoundedReader::new(inner, 6, 2).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    }
+}
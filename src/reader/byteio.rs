@@ -0,0 +1,189 @@
+use std::io::{Read, Result, Seek, SeekFrom};
+
+macro_rules! read_int {
+    ($name:ident, $t:ty, $len:expr, $conv:ident) => {
+        /// Reads a fixed-width integer off the stream.
+        fn $name(&mut self) -> Result<$t> {
+            let mut buf = [0u8; $len];
+            self.read_exact(&mut buf)?;
+            Ok(<$t>::$conv(buf))
+        }
+    };
+}
+
+/// Endian-aware typed reads on top of any [`Read`], so `StreamReaders` (and
+/// every other reader in this crate) can parse fixed-width integers directly
+/// off the stream without a manual scratch buffer at each call site.
+pub trait ByteRead: Read {
+    /// Reads a single byte.
+    fn read_u8(&mut self) -> Result<u8> {
+        let mut buf = [0u8; 1];
+        self.read_exact(&mut buf)?;
+        Ok(buf[0])
+    }
+    /// Reads a single signed byte.
+    fn read_i8(&mut self) -> Result<i8> {
+        Ok(self.read_u8()? as i8)
+    }
+
+    read_int!(read_u16_le, u16, 2, from_le_bytes);
+    read_int!(read_u16_be, u16, 2, from_be_bytes);
+    read_int!(read_i16_le, i16, 2, from_le_bytes);
+    read_int!(read_i16_be, i16, 2, from_be_bytes);
+
+    /// Reads a little-endian 24-bit integer, zero-extended into a `u32`.
+    fn read_u24_le(&mut self) -> Result<u32> {
+        let mut buf = [0u8; 4];
+        self.read_exact(&mut buf[..3])?;
+        Ok(u32::from_le_bytes(buf))
+    }
+    /// Reads a big-endian 24-bit integer, zero-extended into a `u32`.
+    fn read_u24_be(&mut self) -> Result<u32> {
+        let mut buf = [0u8; 4];
+        self.read_exact(&mut buf[1..])?;
+        Ok(u32::from_be_bytes(buf))
+    }
+
+    read_int!(read_u32_le, u32, 4, from_le_bytes);
+    read_int!(read_u32_be, u32, 4, from_be_bytes);
+    read_int!(read_i32_le, i32, 4, from_le_bytes);
+    read_int!(read_i32_be, i32, 4, from_be_bytes);
+
+    read_int!(read_u64_le, u64, 8, from_le_bytes);
+    read_int!(read_u64_be, u64, 8, from_be_bytes);
+    read_int!(read_i64_le, i64, 8, from_le_bytes);
+    read_int!(read_i64_be, i64, 8, from_be_bytes);
+
+    /// Discards the next `n` bytes by reading them into a scratch buffer.
+    fn skip(&mut self, n: u64) -> Result<()> {
+        let mut remaining = n;
+        let mut buf = [0u8; 64];
+        while remaining > 0 {
+            let chunk = remaining.min(buf.len() as u64) as usize;
+            self.read_exact(&mut buf[..chunk])?;
+            remaining -= chunk as u64;
+        }
+        Ok(())
+    }
+}
+
+impl<R: Read> ByteRead for R {}
+
+macro_rules! peek_int {
+    ($name:ident, $read:ident, $t:ty, $width:expr) => {
+        /// Reads with
+        #[doc = concat!("[`", stringify!($read), "`]")]
+        /// then seeks back over it, leaving the stream position unchanged.
+        fn $name(&mut self) -> Result<$t> {
+            let v = self.$read()?;
+            self.seek(SeekFrom::Current(-$width))?;
+            Ok(v)
+        }
+    };
+}
+
+/// [`ByteRead`] companion for seekable streams: look ahead at the next
+/// integer without consuming it from the stream.
+pub trait PeekRead: ByteRead + Seek {
+    peek_int!(peek_u8, read_u8, u8, 1);
+    peek_int!(peek_i8, read_i8, i8, 1);
+
+    peek_int!(peek_u16_le, read_u16_le, u16, 2);
+    peek_int!(peek_u16_be, read_u16_be, u16, 2);
+    peek_int!(peek_i16_le, read_i16_le, i16, 2);
+    peek_int!(peek_i16_be, read_i16_be, i16, 2);
+
+    peek_int!(peek_u24_le, read_u24_le, u32, 3);
+    peek_int!(peek_u24_be, read_u24_be, u32, 3);
+
+    peek_int!(peek_u32_le, read_u32_le, u32, 4);
+    peek_int!(peek_u32_be, read_u32_be, u32, 4);
+    peek_int!(peek_i32_le, read_i32_le, i32, 4);
+    peek_int!(peek_i32_be, read_i32_be, i32, 4);
+
+    peek_int!(peek_u64_le, read_u64_le, u64, 8);
+    peek_int!(peek_u64_be, read_u64_be, u64, 8);
+    peek_int!(peek_i64_le, read_i64_le, i64, 8);
+    peek_int!(peek_i64_be, read_i64_be, i64, 8);
+}
+
+impl<R: ByteRead + Seek> PeekRead for R {}
+
+#[allow(unused)]
+mod test {
+    use super::*;
+    use crate::StreamReaders;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_read_endianness() -> Result<()> {
+        assert_eq!(Cursor::new(vec![0x01, 0x02]).read_u16_le()?, 0x0201);
+        assert_eq!(Cursor::new(vec![0x01, 0x02]).read_u16_be()?, 0x0102);
+        assert_eq!(
+            Cursor::new(vec![0x01, 0x02, 0x03, 0x04]).read_u32_le()?,
+            0x0403_0201
+        );
+        assert_eq!(
+            Cursor::new(vec![0x01, 0x02, 0x03, 0x04]).read_u32_be()?,
+            0x0102_0304
+        );
+        assert_eq!(
+            Cursor::new(vec![1, 2, 3, 4, 5, 6, 7, 8]).read_u64_le()?,
+            0x0807_0605_0403_0201
+        );
+        assert_eq!(
+            Cursor::new(vec![1, 2, 3, 4, 5, 6, 7, 8]).read_u64_be()?,
+            0x0102_0304_0506_0708
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_signed_sign_extends() -> Result<()> {
+        assert_eq!(Cursor::new(vec![0x80]).read_i8()?, -128);
+        assert_eq!(Cursor::new(vec![0xff, 0xff]).read_i16_le()?, -1);
+        assert_eq!(Cursor::new(vec![0xff, 0xff, 0xff, 0xff]).read_i32_be()?, -1);
+        assert_eq!(
+            Cursor::new(vec![0xff; 8]).read_i64_le()?,
+            -1i64
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_u24_zero_extends() -> Result<()> {
+        assert_eq!(Cursor::new(vec![0x01, 0x02, 0x03]).read_u24_le()?, 0x0003_0201);
+        assert_eq!(Cursor::new(vec![0x01, 0x02, 0x03]).read_u24_be()?, 0x0001_0203);
+        Ok(())
+    }
+
+    #[test]
+    fn test_skip() -> Result<()> {
+        let mut r = Cursor::new(vec![1u8, 2, 3, 4, 5]);
+        r.skip(3)?;
+        assert_eq!(r.read_u8()?, 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_peek_does_not_consume() -> Result<()> {
+        let mut r = Cursor::new(vec![0x01, 0x02, 0x03, 0x04]);
+        assert_eq!(r.peek_u32_le()?, 0x0403_0201);
+        assert_eq!(r.read_u32_le()?, 0x0403_0201);
+        Ok(())
+    }
+
+    #[test]
+    fn test_peek_across_stream_readers_segment_boundary() -> Result<()> {
+        let mut readers = StreamReaders::new();
+        readers.push(Cursor::new(b"AB".to_vec()))?;
+        readers.push(Cursor::new(b"CD".to_vec()))?;
+
+        let peeked = readers.peek_u32_le()?;
+        assert_eq!(peeked, u32::from_le_bytes(*b"ABCD"));
+        // Peeking must leave the position unchanged, even across the
+        // boundary between the two pushed segments.
+        assert_eq!(readers.read_u32_le()?, peeked);
+        Ok(())
+    }
+}
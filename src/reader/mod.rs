@@ -3,6 +3,20 @@ use std::{
     io::{Read, Result, Seek, SeekFrom},
 };
 
+mod byteio;
+pub use byteio::{ByteRead, PeekRead};
+
+mod bounded;
+pub use bounded::BoundedReader;
+
+mod seek_bufread;
+pub use seek_bufread::SeekBufReader;
+
+#[cfg(feature = "futures")]
+mod async_reader;
+#[cfg(feature = "futures")]
+pub use async_reader::AsyncStreamReaders;
+
 struct BoxReader<R> {
     reader: R,
     len: u64,
@@ -19,8 +33,12 @@ impl<R: Seek + Read> BoxReader<R> {
 #[derive(Default)]
 pub struct StreamReaders<R> {
     buf: Vec<BoxReader<R>>,
+    /// Index of the segment the cursor currently sits in, or `buf.len()` at EOF.
     index: usize,
+    /// Offset within the current segment (`buf[index]`).
     seek: u64,
+    /// `starts[i]` is the cumulative absolute offset at which `buf[i]` begins.
+    starts: Vec<u64>,
     len: u64,
 }
 
@@ -31,6 +49,7 @@ impl<R: Read + Seek> StreamReaders<R> {
             buf: Vec::new(),
             index: 0,
             seek: 0,
+            starts: Vec::new(),
             len: 0,
         }
     }
@@ -38,6 +57,7 @@ impl<R: Read + Seek> StreamReaders<R> {
     pub fn push(&mut self, value: R) -> Result<()> {
         let reader = BoxReader::new(value)?;
         if reader.len > 0 {
+            self.starts.push(self.len);
             self.len += reader.len;
             self.buf.push(reader);
         }
@@ -52,104 +72,79 @@ impl<R: Read + Seek> StreamReaders<R> {
     /// # Examples
     /// ```
     /// use readers::*;
+    /// use std::io::Cursor;
     /// let bytes = b"hello world";
     /// let mut reader = StreamReaders::new();
-    /// reader.push(BytesReader::new(bytes)).unwrap();
+    /// reader.push(Cursor::new(bytes.as_slice())).unwrap();
     /// assert_eq!(reader.len(), 11)
     /// ```
     pub fn len(&self) -> u64 {
         self.len
     }
-    /// Return the position
+    /// Return the position in O(1), using the cached segment index and the
+    /// prefix-sum `starts` table instead of re-summing every segment.
     pub fn pos(&self) -> u64 {
-        let mut pos = self.seek;
-        for r in &self.buf[..self.index] {
-            pos += r.len;
-        }
-        pos
-    }
-    fn add_offset(&mut self, offset: u64) -> Result<u64> {
-        if self.len > offset + self.pos() {
-            let remain = self.buf[self.index].len - self.seek - 1;
-            if remain >= offset {
-                self.seek = self.buf[self.index]
-                    .reader
-                    .seek(SeekFrom::Current(offset as i64))?;
-            } else {
-                self.index += 1;
-                self.seek = offset - remain - 1;
-                while self.seek > self.buf[self.index].len {
-                    self.seek -= self.buf[self.index].len;
-                    self.index += 1;
-                }
-                self.buf[self.index]
-                    .reader
-                    .seek(SeekFrom::Start(self.seek))?;
-            }
-            Ok(self.pos())
-        } else {
-            self.seek_end()?;
-            Ok(if self.is_empty() { 0 } else { self.len - 1 })
+        match self.starts.get(self.index) {
+            Some(start) => start + self.seek,
+            None => self.len,
         }
     }
-    fn sub_offset(&mut self, offset: u64) -> Result<u64> {
-        if self.pos() >= offset {
-            if self.seek >= offset {
-                self.seek = self.buf[self.index]
-                    .reader
-                    .seek(SeekFrom::Current(-(offset as i64)))?;
-            } else {
-                self.index -= 1;
-                let mut n = offset as i64 - self.seek as i64 - 1;
-                while n < 0 {
-                    n += self.buf[self.index].len as i64;
-                    self.index -= 1;
-                }
-                self.buf[self.index].reader.seek(SeekFrom::End(n.abs()))?;
-            }
-            Ok(self.pos())
-        } else {
-            self.seek_start()?;
-            Ok(0)
+    /// Seek to an absolute position, clamped to `0..=len`, by binary-searching
+    /// the prefix-sum table for the segment that contains it. Only that one
+    /// segment is repositioned; the rest are left untouched and are
+    /// repositioned lazily by `read` as the cursor advances into them.
+    fn seek_to_abs(&mut self, pos: u64) -> Result<u64> {
+        let pos = pos.min(self.len);
+        if self.buf.is_empty() || pos == self.len {
+            self.index = self.buf.len();
+            self.seek = 0;
+            return Ok(pos);
         }
+        let index = match self.starts.binary_search(&pos) {
+            Ok(i) => i,
+            Err(i) => i - 1,
+        };
+        let local = pos - self.starts[index];
+        self.buf[index].reader.seek(SeekFrom::Start(local))?;
+        self.index = index;
+        self.seek = local;
+        Ok(pos)
     }
-    fn seek_start(&mut self) -> Result<()> {
-        self.index = 0;
-        self.seek = 0;
-        for r in &mut self.buf {
-            r.reader.rewind()?;
-        }
-        Ok(())
+    /// Carve out the sub-range `[start, end)` of this stream as an
+    /// independent `BoundedReader`, e.g. one entry inside an archive made of
+    /// several concatenated files.
+    pub fn bounded(self, start: u64, end: u64) -> Result<BoundedReader<Self>> {
+        BoundedReader::new(self, start, end)
     }
+}
 
-    fn seek_end(&mut self) -> Result<()> {
-        if self.buf.is_empty() {
-            return Ok(());
-        }
-        for r in &mut self.buf {
-            r.reader.rewind()?;
+impl<R: Read + Seek> StreamReaders<R> {
+    /// Moves to the next segment and seeks it back to its start, since
+    /// `seek_to_abs` only repositions the segment it lands in and leaves
+    /// every other segment wherever it was last touched.
+    fn advance_segment(&mut self) -> Result<()> {
+        self.index += 1;
+        self.seek = 0;
+        if let Some(segment) = self.buf.get_mut(self.index) {
+            segment.reader.seek(SeekFrom::Start(0))?;
         }
-        self.index = self.buf.len() - 1;
-        self.seek = self.buf[self.index].reader.seek(SeekFrom::End(0))?;
         Ok(())
     }
 }
 
 impl<R: Read + Seek> Read for StreamReaders<R> {
     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
-        if self.pos() >= self.len {
+        if self.index >= self.buf.len() {
             return Ok(0);
         }
         let len = self.buf[self.index].reader.read(buf)?;
         self.seek += len as u64;
         if len < buf.len() {
-            self.index += 1;
-            self.seek = 0;
+            self.advance_segment()?;
             Ok(self.read(&mut buf[len..])? + len)
         } else {
             if self.seek >= self.buf[self.index].len {
-                self.index += 1;
-                self.seek = 0;
+                self.advance_segment()?;
             }
             Ok(len)
         }
@@ -159,25 +154,24 @@ impl<R: Read + Seek> Read for StreamReaders<R> {
 impl<R: Read + Seek> Seek for StreamReaders<R> {
     fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
         match pos {
-            SeekFrom::Current(i) => match i.cmp(&0) {
-                Ordering::Equal => Ok(self.pos()),
-                Ordering::Greater => self.add_offset(i.unsigned_abs()),
-                Ordering::Less => self.sub_offset(i.unsigned_abs()),
+            SeekFrom::Current(i) => {
+                let pos = self.pos();
+                let target = match i.cmp(&0) {
+                    Ordering::Equal => return Ok(pos),
+                    Ordering::Greater => pos.saturating_add(i.unsigned_abs()),
+                    Ordering::Less => pos.saturating_sub(i.unsigned_abs()),
+                };
+                self.seek_to_abs(target)
             }
-            .map_err(Into::into),
             SeekFrom::End(end) => {
-                if end >= 0 {
-                    self.seek_end()?;
-                    Ok(self.len)
+                let target = if end >= 0 {
+                    self.len.saturating_add(end as u64)
                 } else {
-                    self.seek_end()?;
-                    self.sub_offset(end.unsigned_abs()).map_err(Into::into)
-                }
-            }
-            SeekFrom::Start(start) => {
-                self.seek_start()?;
-                self.add_offset(start).map_err(Into::into)
+                    self.len.saturating_sub(end.unsigned_abs())
+                };
+                self.seek_to_abs(target)
             }
+            SeekFrom::Start(start) => self.seek_to_abs(start),
         }
     }
 }
@@ -186,7 +180,7 @@ impl<R: Read + Seek> Seek for StreamReaders<R> {
 mod test {
 
     use super::*;
-    use std::io::BufReader;
+    use std::io::{BufReader, Cursor};
     #[test]
     fn test() -> std::io::Result<()> {
         std::fs::write("1", b"Hello,")?;
@@ -202,4 +196,61 @@ mod test {
         assert_eq!("Hello,Rust!", buf.as_str());
         Ok(())
     }
+
+    fn three_segments() -> std::io::Result<StreamReaders<Cursor<&'static [u8]>>> {
+        let mut readers = StreamReaders::new();
+        readers.push(Cursor::new(b"AAAA".as_ref()))?;
+        readers.push(Cursor::new(b"BBBB".as_ref()))?;
+        readers.push(Cursor::new(b"WXYZ".as_ref()))?;
+        Ok(readers)
+    }
+
+    #[test]
+    fn test_seek_within_segment() -> std::io::Result<()> {
+        let mut readers = three_segments()?;
+        readers.seek(SeekFrom::Start(2))?;
+        let mut buf = [0u8; 2];
+        readers.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"AA");
+
+        readers.seek(SeekFrom::Current(-2))?;
+        readers.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"AA");
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek_across_segment_boundary() -> std::io::Result<()> {
+        let mut readers = three_segments()?;
+        readers.seek(SeekFrom::Start(6))?;
+        let mut buf = [0u8; 4];
+        readers.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"BBWX");
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek_past_end_clamps_to_len() -> std::io::Result<()> {
+        let mut readers = three_segments()?;
+        let pos = readers.seek(SeekFrom::Start(100))?;
+        assert_eq!(pos, readers.len());
+        let mut buf = [0u8; 1];
+        assert_eq!(readers.read(&mut buf)?, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek_then_read_from_start_reseeks_touched_segments() -> std::io::Result<()> {
+        let mut readers = three_segments()?;
+        // Touch the 3rd segment and leave its reader mid-segment.
+        readers.seek(SeekFrom::Start(9))?;
+        let mut one = [0u8; 1];
+        readers.read_exact(&mut one)?;
+
+        readers.seek(SeekFrom::Start(0))?;
+        let mut buf = Vec::new();
+        readers.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"AAAABBBBWXYZ");
+        Ok(())
+    }
 }
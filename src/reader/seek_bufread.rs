@@ -0,0 +1,184 @@
+use std::io::{Read, Result, Seek, SeekFrom};
+
+const DEFAULT_BUF_SIZE: usize = 8 * 1024;
+
+/// A buffered reader whose `Seek` is cheap when the target stays within the
+/// already-filled buffer.
+///
+/// `std::io::BufReader` throws its buffer away on every seek, which also
+/// repositions the underlying reader even for a seek of a few bytes. This
+/// wrapper tracks the absolute position of its buffer (`buf_start` through
+/// `buf_start + cap`) and only discards it and touches the inner reader when
+/// the seek target actually falls outside that window, making
+/// backward/forward scrubbing over a stream cheap. `SeekFrom::End` always
+/// discards the buffer and goes to the inner reader.
+pub struct SeekBufReader<R> {
+    inner: R,
+    buf: Box<[u8]>,
+    /// Read cursor, as an index into `buf`.
+    pos: usize,
+    /// Number of valid bytes in `buf`, starting at index `0`.
+    cap: usize,
+    /// Absolute position of `buf[0]` in the underlying stream.
+    buf_start: u64,
+}
+
+impl<R: Read + Seek> SeekBufReader<R> {
+    /// Creates a `SeekBufReader` with the default buffer capacity.
+    pub fn new(inner: R) -> Result<SeekBufReader<R>> {
+        Self::with_capacity(DEFAULT_BUF_SIZE, inner)
+    }
+    /// Creates a `SeekBufReader` with the given buffer capacity.
+    pub fn with_capacity(capacity: usize, mut inner: R) -> Result<SeekBufReader<R>> {
+        let buf_start = inner.stream_position()?;
+        Ok(SeekBufReader {
+            inner,
+            buf: vec![0; capacity].into_boxed_slice(),
+            pos: 0,
+            cap: 0,
+            buf_start,
+        })
+    }
+    /// Absolute position of the read cursor in the underlying stream.
+    fn current_pos(&self) -> u64 {
+        self.buf_start + self.pos as u64
+    }
+    /// Invalidates the buffer, anchoring `buf_start` at the current position.
+    fn discard_buffer(&mut self) {
+        self.buf_start = self.current_pos();
+        self.pos = 0;
+        self.cap = 0;
+    }
+    fn fill_buf(&mut self) -> Result<&[u8]> {
+        if self.pos >= self.cap {
+            self.buf_start += self.cap as u64;
+            self.cap = self.inner.read(&mut self.buf)?;
+            self.pos = 0;
+        }
+        Ok(&self.buf[self.pos..self.cap])
+    }
+}
+
+impl<R: Read + Seek> Read for SeekBufReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        // Bypass the buffer for large reads when it's empty, same as
+        // `std::io::BufReader`: buffering first would just add a copy.
+        if self.pos == self.cap && buf.len() >= self.buf.len() {
+            self.discard_buffer();
+            let n = self.inner.read(buf)?;
+            self.buf_start += n as u64;
+            return Ok(n);
+        }
+        let avail = self.fill_buf()?;
+        let n = avail.len().min(buf.len());
+        buf[..n].copy_from_slice(&avail[..n]);
+        self.pos += n;
+        Ok(n)
+    }
+}
+
+impl<R: Read + Seek> Seek for SeekBufReader<R> {
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        let target = match pos {
+            SeekFrom::Start(n) => n,
+            SeekFrom::Current(n) => {
+                let cur = self.current_pos();
+                if n >= 0 {
+                    cur.saturating_add(n as u64)
+                } else {
+                    cur.saturating_sub(n.unsigned_abs())
+                }
+            }
+            // The end of the stream isn't cached, so there's no way to
+            // resolve this without asking the inner reader.
+            SeekFrom::End(_) => {
+                self.discard_buffer();
+                let abs = self.inner.seek(pos)?;
+                self.buf_start = abs;
+                return Ok(abs);
+            }
+        };
+        if target >= self.buf_start && target <= self.buf_start + self.cap as u64 {
+            self.pos = (target - self.buf_start) as usize;
+        } else {
+            self.discard_buffer();
+            self.buf_start = self.inner.seek(SeekFrom::Start(target))?;
+        }
+        Ok(target)
+    }
+}
+
+#[allow(unused)]
+mod test {
+    use super::*;
+    use std::{cell::Cell, io::Cursor, rc::Rc};
+
+    struct CountingReader<R> {
+        inner: R,
+        seeks: Rc<Cell<u32>>,
+    }
+
+    impl<R: Read> Read for CountingReader<R> {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+            self.inner.read(buf)
+        }
+    }
+
+    impl<R: Seek> Seek for CountingReader<R> {
+        fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+            self.seeks.set(self.seeks.get() + 1);
+            self.inner.seek(pos)
+        }
+    }
+
+    #[test]
+    fn test_read_fills_buffer_and_advances_position() -> Result<()> {
+        let data: Vec<u8> = (0u8..32).collect();
+        let mut reader = SeekBufReader::with_capacity(8, Cursor::new(data))?;
+        let mut buf = [0u8; 4];
+        reader.read_exact(&mut buf)?;
+        assert_eq!(buf, [0, 1, 2, 3]);
+        reader.read_exact(&mut buf)?;
+        assert_eq!(buf, [4, 5, 6, 7]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_in_buffer_seek_does_not_touch_inner_reader() -> Result<()> {
+        let data: Vec<u8> = (0u8..32).collect();
+        let seeks = Rc::new(Cell::new(0));
+        let inner = CountingReader {
+            inner: Cursor::new(data),
+            seeks: seeks.clone(),
+        };
+        let mut reader = SeekBufReader::with_capacity(8, inner)?;
+
+        let mut buf = [0u8; 4];
+        reader.read_exact(&mut buf)?;
+        let seeks_after_fill = seeks.get();
+
+        // Seeking within the still-buffered window must not touch the inner reader.
+        reader.seek(SeekFrom::Start(6))?;
+        assert_eq!(seeks.get(), seeks_after_fill);
+        let mut one = [0u8; 1];
+        reader.read_exact(&mut one)?;
+        assert_eq!(one, [6]);
+
+        // Seeking outside the buffered window does touch the inner reader.
+        reader.seek(SeekFrom::Start(20))?;
+        assert!(seeks.get() > seeks_after_fill);
+        reader.read_exact(&mut one)?;
+        assert_eq!(one, [20]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_large_read_bypasses_buffer() -> Result<()> {
+        let data: Vec<u8> = (0u8..32).collect();
+        let mut reader = SeekBufReader::with_capacity(8, Cursor::new(data.clone()))?;
+        let mut buf = [0u8; 16];
+        reader.read_exact(&mut buf)?;
+        assert_eq!(buf.as_slice(), &data[..16]);
+        Ok(())
+    }
+}